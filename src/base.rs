@@ -1,4 +1,7 @@
-use std::{error::Error, marker::PhantomData};
+use std::{error::Error, fmt, marker::PhantomData};
+
+use crate::context::Contextual;
+use crate::severity::{Severed, Severity};
 
 /// Sink to which the non-fatal errors of type `E` can be written
 pub trait Warn<E: Error> {
@@ -12,6 +15,23 @@ pub struct Adapt<'a, E, W>(&'a mut W, PhantomData<E>);
 /// Sink adapter that applies some function before passing the value to the wrapped sink
 pub struct AdaptMap<'a, D, E, F, W>(&'a mut W, F, PhantomData<D>, PhantomData<E>);
 
+/// Sink adapter that drops errors for which the predicate `F` returns `false`
+pub struct Filter<'a, E, F, W>(&'a mut W, F, PhantomData<E>);
+
+/// Sink adapter that runs a side effect on each error before forwarding it unchanged
+pub struct Inspect<'a, E, F, W>(&'a mut W, F, PhantomData<E>);
+
+/// Sink adapter that forwards each error to two sinks, cloning it for the first one
+pub struct Tee<'a, E, A, B>(&'a mut A, &'a mut B, PhantomData<E>);
+
+/// Sink adapter that attaches contextual information to every incoming error, wrapping it into
+/// [`Contextual`]
+pub struct Context<'a, C, D, W>(&'a mut W, C, PhantomData<D>);
+
+/// Sink adapter that tags every incoming error with an explicit [`Severity`], wrapping it into
+/// [`Severed`]
+pub struct WithSeverity<'a, D, W>(&'a mut W, Severity, PhantomData<D>);
+
 mod sealed {
     use std::error::Error;
 
@@ -83,6 +103,69 @@ pub trait WarnExt<E: Error>: Warn<E> + sealed::WarnExt<E> {
     {
         AdaptMap(self, func, PhantomData, PhantomData)
     }
+
+    /// Wraps the sink into an adapter which drops every error for which `pred` returns `false`
+    #[inline]
+    fn filter<F>(&mut self, pred: F) -> Filter<'_, E, F, Self>
+    where
+        Self: Sized,
+        F: FnMut(&E) -> bool,
+    {
+        Filter(self, pred, PhantomData)
+    }
+
+    /// Wraps the sink into an adapter which runs `func` on each error before forwarding it
+    /// unchanged
+    #[inline]
+    fn inspect<F>(&mut self, func: F) -> Inspect<'_, E, F, Self>
+    where
+        Self: Sized,
+        F: FnMut(&E),
+    {
+        Inspect(self, func, PhantomData)
+    }
+
+    /// Wraps the sink into an adapter which forwards each error both to this sink and to `other`
+    ///
+    /// The error is cloned, so this requires `E: Clone`.
+    #[inline]
+    fn tee<'a, O>(&'a mut self, other: &'a mut O) -> Tee<'a, E, Self, O>
+    where
+        Self: Sized,
+        O: Warn<E>,
+        E: Clone,
+    {
+        Tee(self, other, PhantomData)
+    }
+
+    /// Wraps the sink into an adapter which attaches `ctx` to every incoming error, turning it
+    /// into a [`Contextual`] before passing it to the wrapped sink
+    ///
+    /// This is useful to give warnings call-site meaning when several subfunctions funnel into
+    /// one sink, e.g. `warn.context("while parsing config section X")`.
+    #[inline]
+    fn context<C, D>(&mut self, ctx: C) -> Context<'_, C, D, Self>
+    where
+        Self: Sized + Warn<Contextual<C, D>>,
+        C: Clone + fmt::Debug + fmt::Display,
+        D: Error + 'static,
+    {
+        Context(self, ctx, PhantomData)
+    }
+
+    /// Wraps the sink into an adapter which tags every incoming error with `level`, turning it
+    /// into a [`Severed`] before passing it to the wrapped sink
+    ///
+    /// This lets [`FailFast`](crate::sink::FailFast) escalate on errors whose type doesn't
+    /// implement [`HasSeverity`](crate::HasSeverity) itself.
+    #[inline]
+    fn with_severity<D>(&mut self, level: Severity) -> WithSeverity<'_, D, Self>
+    where
+        Self: Sized + Warn<Severed<D>>,
+        D: Error + 'static,
+    {
+        WithSeverity(self, level, PhantomData)
+    }
 }
 
 impl<E: Error, W: Warn<E>> WarnExt<E> for W {}
@@ -111,3 +194,66 @@ where
         self.0.warn(self.1(error))
     }
 }
+
+impl<'a, E, F, W> Warn<E> for Filter<'a, E, F, W>
+where
+    E: Error,
+    F: FnMut(&E) -> bool,
+    W: Warn<E>,
+{
+    #[inline]
+    fn warn(&mut self, error: E) {
+        if self.1(&error) {
+            self.0.warn(error);
+        }
+    }
+}
+
+impl<'a, E, F, W> Warn<E> for Inspect<'a, E, F, W>
+where
+    E: Error,
+    F: FnMut(&E),
+    W: Warn<E>,
+{
+    #[inline]
+    fn warn(&mut self, error: E) {
+        self.1(&error);
+        self.0.warn(error);
+    }
+}
+
+impl<'a, E, A, B> Warn<E> for Tee<'a, E, A, B>
+where
+    E: Error + Clone,
+    A: Warn<E>,
+    B: Warn<E>,
+{
+    #[inline]
+    fn warn(&mut self, error: E) {
+        self.0.warn(error.clone());
+        self.1.warn(error);
+    }
+}
+
+impl<'a, C, D, W> Warn<D> for Context<'a, C, D, W>
+where
+    C: Clone + fmt::Debug + fmt::Display,
+    D: Error + 'static,
+    W: Warn<Contextual<C, D>>,
+{
+    #[inline]
+    fn warn(&mut self, error: D) {
+        self.0.warn(Contextual::new(self.1.clone(), error))
+    }
+}
+
+impl<'a, D, W> Warn<D> for WithSeverity<'a, D, W>
+where
+    D: Error + 'static,
+    W: Warn<Severed<D>>,
+{
+    #[inline]
+    fn warn(&mut self, error: D) {
+        self.0.warn(Severed::new(self.1, error))
+    }
+}
@@ -0,0 +1,48 @@
+//! Error type for attaching contextual information to non-fatal errors
+
+use std::error::Error;
+use std::fmt;
+
+/// Error which wraps another error together with some contextual information
+///
+/// Created by [`WarnExt::context`](crate::WarnExt::context) and
+/// [`ResultExt::or_warn_context`](crate::ResultExt::or_warn_context) to attach a human-readable
+/// description (e.g. "while parsing config section X") to an error as it bubbles up through
+/// several layers of subfunctions, without losing the original error.
+#[derive(Debug)]
+pub struct Contextual<C, E> {
+    ctx: C,
+    source: E,
+}
+
+impl<C, E> Contextual<C, E> {
+    /// Creates a new contextual error, attaching `ctx` to `source`
+    #[inline]
+    pub fn new(ctx: C, source: E) -> Self {
+        Self { ctx, source }
+    }
+
+    /// Returns the attached context
+    #[inline]
+    pub fn context(&self) -> &C {
+        &self.ctx
+    }
+
+    /// Returns the original error
+    #[inline]
+    pub fn source_error(&self) -> &E {
+        &self.source
+    }
+}
+
+impl<C: fmt::Display, E: fmt::Display> fmt::Display for Contextual<C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.ctx, self.source)
+    }
+}
+
+impl<C: fmt::Debug + fmt::Display, E: Error + 'static> Error for Contextual<C, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -1,13 +1,17 @@
 use std::error::Error;
+use std::fmt;
 
 use crate::base::Warn;
+use crate::context::Contextual;
 
 mod sealed {
     pub trait Option {}
     pub trait Result<T, E> {}
+    pub trait Iterator {}
 
     impl<T> Option for std::option::Option<T> {}
     impl<T, E> Result<T, E> for std::result::Result<T, E> {}
+    impl<I: std::iter::Iterator> Iterator for I {}
 }
 
 /// Integration between [`Option`] and [`Warn`]
@@ -36,6 +40,26 @@ pub trait ResultExt<T, E: Error>: sealed::Result<T, E> {
     /// into `warn`
     fn or_warn_map<D: Error>(self, func: impl FnOnce(E) -> D, warn: &mut impl Warn<D>)
         -> Option<T>;
+
+    /// Same as [`ResultExt::or_warn()`], but wraps the error together with `ctx` into a
+    /// [`Contextual`] before passing into `warn`
+    fn or_warn_context<C>(self, ctx: C, warn: &mut impl Warn<Contextual<C, E>>) -> Option<T>
+    where
+        C: fmt::Debug + fmt::Display,
+        E: 'static;
+}
+
+/// Integration between [`Iterator`] and [`Warn`]
+///
+/// Note that this trait is sealed, so it is implemented for any `Iterator<Item = Result<T, E>>`
+/// and cannot be implemented for anything else.
+pub trait IteratorExt<T, E: Error>: Iterator<Item = Result<T, E>> + sealed::Iterator {
+    /// Drains the iterator, pushing every `Err` into `warn` and collecting the `Ok` values
+    ///
+    /// Unlike [`Result`]'s [`FromIterator`](std::iter::FromIterator) impl, this does not
+    /// short-circuit on the first error: every item in the iterator is consumed, and every
+    /// error is forwarded to `warn`.
+    fn collect_warn<D: From<E> + Error>(self, warn: &mut impl Warn<D>) -> Vec<T>;
 }
 
 impl<T> OptionExt for Option<T> {
@@ -68,4 +92,30 @@ impl<T, E: Error> ResultExt<T, E> for Result<T, E> {
             }
         }
     }
+
+    #[inline]
+    fn or_warn_context<C>(self, ctx: C, warn: &mut impl Warn<Contextual<C, E>>) -> Option<T>
+    where
+        C: fmt::Debug + fmt::Display,
+        E: 'static,
+    {
+        match self {
+            Ok(val) => Some(val),
+            Err(err) => {
+                warn.warn(Contextual::new(ctx, err));
+                None
+            }
+        }
+    }
+}
+
+impl<I, T, E> IteratorExt<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: Error,
+{
+    #[inline]
+    fn collect_warn<D: From<E> + Error>(self, warn: &mut impl Warn<D>) -> Vec<T> {
+        self.filter_map(|item| item.or_warn(warn)).collect()
+    }
 }
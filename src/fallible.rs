@@ -0,0 +1,82 @@
+//! Sinks which can themselves fail while recording a non-fatal error
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::marker::PhantomData;
+
+use crate::base::Warn;
+
+/// Sink to which the non-fatal errors of type `E` can be written, but which can itself fail
+///
+/// Unlike [`Warn`], pushing an error into a `TryWarn` sink is fallible: a sink that writes each
+/// error to a file, a socket, or a structured-logging backend can fail too, and that failure
+/// is returned to the caller instead of being lost.
+///
+/// Every [`Warn`] sink can be trivially turned into a `TryWarn` sink with `SinkError =
+/// Infallible` by wrapping it into [`AsTryWarn`].
+pub trait TryWarn<E: Error> {
+    /// The error returned when writing to this sink itself fails
+    type SinkError: Error;
+
+    /// Push the error `error` to the sink, returning `Err` if the sink itself failed
+    fn try_warn(&mut self, error: E) -> Result<(), Self::SinkError>;
+}
+
+/// Wraps any infallible [`Warn`] sink, making it trivially a [`TryWarn`] sink with
+/// `SinkError = Infallible`
+#[derive(Debug, Clone)]
+pub struct AsTryWarn<W>(pub W);
+
+impl<E: Error, W: Warn<E>> TryWarn<E> for AsTryWarn<W> {
+    type SinkError = Infallible;
+
+    #[inline]
+    fn try_warn(&mut self, error: E) -> Result<(), Infallible> {
+        self.0.warn(error);
+        Ok(())
+    }
+}
+
+mod sealed {
+    use std::error::Error;
+
+    pub trait TryWarnExt<E> {}
+
+    impl<E: Error, W: super::TryWarn<E>> TryWarnExt<E> for W {}
+}
+
+/// Extension methods for trait [`TryWarn`]
+///
+/// This trait is implemented for all the traits which implement [`TryWarn`]. Note that this
+/// trait is sealed, so you cannot implement it for anything else.
+pub trait TryWarnExt<E: Error>: TryWarn<E> + sealed::TryWarnExt<E> {
+    /// Turns this fallible sink into an infallible [`Warn`] sink, routing any sink failure into
+    /// `fallback`
+    #[inline]
+    fn or_else<'a, F>(&'a mut self, fallback: &'a mut F) -> OrElse<'a, E, Self, F>
+    where
+        Self: Sized,
+        F: Warn<Self::SinkError>,
+    {
+        OrElse(self, fallback, PhantomData)
+    }
+}
+
+impl<E: Error, W: TryWarn<E>> TryWarnExt<E> for W {}
+
+/// [`Warn`] adapter produced by [`TryWarnExt::or_else`]
+pub struct OrElse<'a, E, W, F>(&'a mut W, &'a mut F, PhantomData<E>);
+
+impl<'a, E, W, F> Warn<E> for OrElse<'a, E, W, F>
+where
+    E: Error,
+    W: TryWarn<E>,
+    F: Warn<W::SinkError>,
+{
+    #[inline]
+    fn warn(&mut self, error: E) {
+        if let Err(sink_error) = self.0.try_warn(error) {
+            self.1.warn(sink_error);
+        }
+    }
+}
@@ -85,12 +85,20 @@
 //! assert_eq!(warn.0.len(), 4);
 //! ```
 mod base;
+mod context;
 mod ext;
+mod fallible;
+mod location;
+mod severity;
 
 pub mod sink;
 
-pub use base::{Adapt, AdaptMap, Warn, WarnExt};
-pub use ext::{OptionExt, ResultExt};
+pub use base::{Adapt, AdaptMap, Context, Filter, Inspect, Tee, Warn, WarnExt, WithSeverity};
+pub use context::Contextual;
+pub use ext::{IteratorExt, OptionExt, ResultExt};
+pub use fallible::{AsTryWarn, OrElse, TryWarn, TryWarnExt};
+pub use location::{warn_at, Located};
+pub use severity::{HasSeverity, Severed, Severity};
 #[allow(deprecated)]
 pub use sink::{All, CollectAll, Ignore, Stderr};
 
@@ -103,7 +111,7 @@ pub use sink::{All, CollectAll, Ignore, Stderr};
 /// use wurm::prelude::*;
 /// ```
 pub mod prelude {
-    pub use crate::{OptionExt, ResultExt, Warn, WarnExt};
+    pub use crate::{IteratorExt, OptionExt, ResultExt, TryWarnExt, Warn, WarnExt};
 }
 
 #[cfg(test)]
@@ -112,7 +120,7 @@ mod tests {
 
     use thiserror::Error;
 
-    #[derive(Debug, Error, Eq, PartialEq)]
+    #[derive(Debug, Error, Clone, Eq, PartialEq)]
     #[error("first: {value}")]
     struct ErrFirst {
         value: usize,
@@ -192,4 +200,181 @@ mod tests {
         assert_eq!(value.or_warn(&mut warn), None);
         assert_eq!(warn.0.len(), 2);
     }
+
+    #[test]
+    fn test_collect_warn() {
+        let mut warn: CollectAll<ErrFirst> = CollectAll::default();
+        let items: Vec<Result<usize, ErrFirst>> = vec![
+            Ok(1),
+            Err(ErrFirst { value: 2 }),
+            Ok(3),
+            Err(ErrFirst { value: 4 }),
+            Ok(5),
+        ];
+        let values = items.into_iter().collect_warn(&mut warn);
+        assert_eq!(values, vec![1, 3, 5]);
+        assert_eq!(
+            warn.0,
+            vec![ErrFirst { value: 2 }, ErrFirst { value: 4 }]
+        );
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut warn = CollectAll::default();
+        warn.filter(|e: &ErrFirst| e.value > 1)
+            .warn(ErrFirst { value: 1 });
+        assert_eq!(warn.0.len(), 0);
+
+        warn.filter(|e: &ErrFirst| e.value > 1)
+            .warn(ErrFirst { value: 2 });
+        assert_eq!(warn.0, vec![ErrFirst { value: 2 }]);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut warn = CollectAll::default();
+        let mut seen = Vec::new();
+        warn.inspect(|e: &ErrFirst| seen.push(e.value))
+            .warn(ErrFirst { value: 3 });
+        assert_eq!(seen, vec![3]);
+        assert_eq!(warn.0, vec![ErrFirst { value: 3 }]);
+    }
+
+    #[test]
+    fn test_tee() {
+        let mut a = CollectAll::default();
+        let mut b = CollectAll::default();
+        a.tee(&mut b).warn(ErrFirst { value: 4 });
+        assert_eq!(a.0, vec![ErrFirst { value: 4 }]);
+        assert_eq!(b.0, vec![ErrFirst { value: 4 }]);
+    }
+
+    #[test]
+    fn test_context() {
+        use std::error::Error as _;
+
+        let err = Contextual::new("while parsing", ErrFirst { value: 1 });
+        assert_eq!(err.context(), &"while parsing");
+        assert_eq!(err.source_error(), &ErrFirst { value: 1 });
+        assert_eq!(err.to_string(), "while parsing: first: 1");
+        let source = err.source().expect("should have a source");
+        assert_eq!(source.downcast_ref::<ErrFirst>(), Some(&ErrFirst { value: 1 }));
+
+        let mut warn: CollectAll<Contextual<&str, ErrFirst>> = CollectAll::default();
+        warn.context("while baring").warn(ErrFirst { value: 2 });
+        assert_eq!(warn.0.len(), 1);
+        assert_eq!(warn.0[0].context(), &"while baring");
+        assert_eq!(warn.0[0].source_error(), &ErrFirst { value: 2 });
+
+        let value: Result<usize, ErrFirst> = Err(ErrFirst { value: 3 });
+        assert_eq!(value.or_warn_context("while quuxing", &mut warn), None);
+        assert_eq!(warn.0.len(), 2);
+        assert_eq!(warn.0[1].context(), &"while quuxing");
+    }
+
+    #[test]
+    fn test_fanout() {
+        let mut warn = sink::fanout(CollectAll::default(), CollectAll::default());
+        warn.warn(ErrFirst { value: 5 });
+        assert_eq!((warn.0).0, vec![ErrFirst { value: 5 }]);
+        assert_eq!((warn.1).0, vec![ErrFirst { value: 5 }]);
+    }
+
+    #[test]
+    fn test_fail_fast_below_threshold() {
+        let mut fail_fast: sink::FailFast<Severed<ErrFirst>> = sink::FailFast::new(Severity::Error);
+        {
+            let mut warn = fail_fast.with_severity(Severity::Warning);
+            warn.warn(ErrFirst { value: 1 });
+        }
+        assert_eq!(fail_fast.errors.len(), 1);
+        assert!(matches!(fail_fast.into_result(42), Ok(42)));
+    }
+
+    #[test]
+    fn test_fail_fast_escalates() {
+        let mut fail_fast: sink::FailFast<Severed<ErrFirst>> = sink::FailFast::new(Severity::Error);
+        {
+            let mut warn = fail_fast.with_severity(Severity::Warning);
+            warn.warn(ErrFirst { value: 1 });
+        }
+        {
+            let mut warn = fail_fast.with_severity(Severity::Fatal);
+            warn.warn(ErrFirst { value: 2 });
+        }
+        {
+            let mut warn = fail_fast.with_severity(Severity::Warning);
+            warn.warn(ErrFirst { value: 3 });
+        }
+        assert_eq!(fail_fast.errors.len(), 3);
+        match fail_fast.into_result(()) {
+            Err(e) => assert_eq!(e.source_error(), &ErrFirst { value: 2 }),
+            Ok(()) => panic!("expected escalation at the fatal error"),
+        }
+    }
+
+    #[test]
+    fn test_located() {
+        let mut warn = sink::WithLocation(CollectAll::default());
+        warn.warn(ErrFirst { value: 1 });
+        let line = line!() - 1;
+        assert_eq!(warn.0 .0.len(), 1);
+        assert_eq!(warn.0 .0[0].source_error(), &ErrFirst { value: 1 });
+        assert_eq!(warn.0 .0[0].location().line(), line);
+        assert_eq!(warn.0 .0[0].location().file(), file!());
+    }
+
+    #[test]
+    fn test_warn_at() {
+        let mut warn: CollectAll<Located<ErrFirst>> = CollectAll::default();
+        warn_at(&mut warn, ErrFirst { value: 2 });
+        let line = line!() - 1;
+        assert_eq!(warn.0.len(), 1);
+        assert_eq!(warn.0[0].location().line(), line);
+    }
+
+    struct FailingWriter;
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("write failed"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_writer() {
+        let mut writer = sink::Writer(Vec::new());
+        writer
+            .try_warn(ErrFirst { value: 1 })
+            .expect("write should succeed");
+        assert_eq!(writer.0, b"first: 1\n");
+
+        let mut failing = sink::Writer(FailingWriter);
+        let err = failing
+            .try_warn(ErrFirst { value: 2 })
+            .expect_err("write should fail");
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_as_try_warn() {
+        let mut warn = AsTryWarn(CollectAll::default());
+        warn.try_warn(ErrFirst { value: 1 }).expect("infallible");
+        assert_eq!(warn.0 .0, vec![ErrFirst { value: 1 }]);
+    }
+
+    #[test]
+    fn test_or_else() {
+        let mut writer = sink::Writer(FailingWriter);
+        let mut fallback: CollectAll<std::io::Error> = CollectAll::default();
+        let mut warn = writer.or_else(&mut fallback);
+        warn.warn(ErrFirst { value: 3 });
+        assert_eq!(fallback.0.len(), 1);
+        assert_eq!(fallback.0[0].kind(), std::io::ErrorKind::Other);
+    }
 }
@@ -0,0 +1,63 @@
+//! Error type and helpers for capturing the call-site location of a non-fatal error
+
+use std::error::Error;
+use std::fmt;
+use std::panic::Location;
+
+use crate::base::Warn;
+
+/// Error which records where it originated, via [`Location`]
+///
+/// Created by [`WithLocation`](crate::sink::WithLocation) and [`warn_at`] to turn the collected
+/// warnings into something actionable for diagnostics.
+#[derive(Debug)]
+pub struct Located<E> {
+    location: &'static Location<'static>,
+    source: E,
+}
+
+impl<E> Located<E> {
+    /// Creates a new located error, capturing the caller's location
+    #[track_caller]
+    #[inline]
+    pub fn new(source: E) -> Self {
+        Self {
+            location: Location::caller(),
+            source,
+        }
+    }
+
+    /// Returns the location where the error was raised
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the original error
+    #[inline]
+    pub fn source_error(&self) -> &E {
+        &self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.source)
+    }
+}
+
+impl<E: Error + 'static> Error for Located<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Pushes `error` into `warn`, capturing the caller's location into a [`Located`] wrapper
+///
+/// This is `#[track_caller]`, so the recorded location is the `warn_at(...)` call site itself,
+/// not somewhere inside `wurm`.
+#[track_caller]
+#[inline]
+pub fn warn_at<E: Error + 'static>(warn: &mut impl Warn<Located<E>>, error: E) {
+    warn.warn(Located::new(error));
+}
@@ -0,0 +1,74 @@
+//! Severity levels for non-fatal errors, with the ability to escalate into a fatal failure
+
+use std::error::Error;
+use std::fmt;
+
+/// Severity level of a non-fatal error
+///
+/// Used by [`FailFast`](crate::sink::FailFast) to decide whether an incoming error should
+/// escalate the whole run into a hard failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Merely informative; the run can safely continue
+    Warning,
+    /// Recoverable, but should be looked at
+    Error,
+    /// Unrecoverable; the whole operation should be considered failed
+    Fatal,
+}
+
+/// Provides the [`Severity`] of an error
+///
+/// The default severity is [`Severity::Warning`], so implementing this trait for an existing
+/// error type only requires an empty `impl HasSeverity for MyError {}`, unless a different
+/// default is needed. See [`WarnExt::with_severity`](crate::WarnExt::with_severity) to tag an
+/// error with an explicit severity without implementing this trait at all.
+pub trait HasSeverity: Error {
+    /// Returns the severity of this error
+    #[inline]
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
+
+/// Error which tags another error with an explicit [`Severity`]
+///
+/// Created by [`WarnExt::with_severity`](crate::WarnExt::with_severity).
+#[derive(Debug)]
+pub struct Severed<E> {
+    severity: Severity,
+    source: E,
+}
+
+impl<E> Severed<E> {
+    /// Creates a new error, tagging `source` with `severity`
+    #[inline]
+    pub fn new(severity: Severity, source: E) -> Self {
+        Self { severity, source }
+    }
+
+    /// Returns the original error
+    #[inline]
+    pub fn source_error(&self) -> &E {
+        &self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Severed<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.source, f)
+    }
+}
+
+impl<E: Error + 'static> Error for Severed<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl<E: Error + 'static> HasSeverity for Severed<E> {
+    #[inline]
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+}
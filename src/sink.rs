@@ -2,9 +2,13 @@
 //!
 //! Of course, all of these sinks implement [`Warn`].
 
+use std::io;
 use std::{error::Error, marker::PhantomData};
 
 use crate::base::Warn;
+use crate::fallible::TryWarn;
+use crate::location::Located;
+use crate::severity::{HasSeverity, Severity};
 
 /// Ignores all the incoming errors
 #[derive(Debug, Clone)]
@@ -99,6 +103,99 @@ impl<E: Error, F: FnMut(E)> Warn<E> for FromFn<E, F> {
     }
 }
 
+/// Sink which forwards each error to both of the wrapped sinks, cloning the error for the
+/// first one
+#[derive(Debug, Clone)]
+pub struct Fanout<A, B>(pub A, pub B);
+
+/// Creates a sink which forwards each error to both `a` and `b`
+#[inline]
+pub fn fanout<A, B>(a: A, b: B) -> Fanout<A, B> {
+    Fanout(a, b)
+}
+
+impl<E: Error + Clone, A: Warn<E>, B: Warn<E>> Warn<E> for Fanout<A, B> {
+    #[inline]
+    fn warn(&mut self, error: E) {
+        self.0.warn(error.clone());
+        self.1.warn(error);
+    }
+}
+
+/// Sink wrapper which records the call-site location of every incoming error before forwarding
+/// it to the wrapped sink as a [`Located`]
+#[derive(Debug, Clone)]
+pub struct WithLocation<W>(pub W);
+
+impl<E: Error + 'static, W: Warn<Located<E>>> Warn<E> for WithLocation<W> {
+    #[track_caller]
+    #[inline]
+    fn warn(&mut self, error: E) {
+        self.0.warn(Located::new(error));
+    }
+}
+
+/// Collects all incoming errors like [`CollectAll`], but remembers the first one whose severity
+/// reaches a configured threshold, so the whole run can be converted into a [`Result`]
+#[derive(Debug, Clone)]
+pub struct FailFast<E: HasSeverity> {
+    /// All the errors collected so far, in arrival order
+    pub errors: Vec<E>,
+    threshold: Severity,
+    failed_at: Option<usize>,
+}
+
+impl<E: HasSeverity> FailFast<E> {
+    /// Creates a new sink which escalates into failure once an error at or above `threshold`
+    /// severity arrives
+    #[inline]
+    pub fn new(threshold: Severity) -> Self {
+        Self {
+            errors: Vec::new(),
+            threshold,
+            failed_at: None,
+        }
+    }
+
+    /// Converts the run into a [`Result`]
+    ///
+    /// Returns `Ok(value)` if no collected error reached the threshold severity, or `Err` with
+    /// the first error that did.
+    pub fn into_result<T>(mut self, value: T) -> Result<T, E> {
+        match self.failed_at {
+            Some(idx) => Err(self.errors.swap_remove(idx)),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<E: HasSeverity> Warn<E> for FailFast<E> {
+    #[inline]
+    fn warn(&mut self, error: E) {
+        if self.failed_at.is_none() && error.severity() >= self.threshold {
+            self.failed_at = Some(self.errors.len());
+        }
+        self.errors.push(error);
+    }
+}
+
+/// Sink which serializes each incoming error (via [`Display`](std::fmt::Display)) to a writer,
+/// one per line
+///
+/// Unlike the other sinks in this module, writing can fail, so this implements [`TryWarn`]
+/// rather than [`Warn`], surfacing the underlying [`io::Error`] instead of swallowing it.
+#[derive(Debug, Clone)]
+pub struct Writer<W>(pub W);
+
+impl<E: Error, W: io::Write> TryWarn<E> for Writer<W> {
+    type SinkError = io::Error;
+
+    #[inline]
+    fn try_warn(&mut self, error: E) -> Result<(), io::Error> {
+        writeln!(self.0, "{}", error)
+    }
+}
+
 /// Deprecated alias to [`CollectAll`]
 #[deprecated(since = "1.1.0", note = "deprecated to make the name less confusing, use CollectAll instead")]
 pub type All<E> = CollectAll<E>;